@@ -2,16 +2,26 @@ use crate::views::{centered_rect, render_line_numbers, Env, Request, View, ViewC
 use chrono::{DateTime, Utc};
 use crossterm::event::KeyCode;
 use eventstore::{RecordedEvent, ResolvedEvent, StreamPosition};
+use regex::Regex;
 use std::ops::Add;
 use std::time::{Duration, SystemTime};
 use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
-use tui::text::Text;
+use tui::text::{Span, Spans, Text};
 use tui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
 use tui::Frame;
 
 static HEADERS: &[&'static str] = &["Recently Created Streams", "Recently Changed Streams"];
 static STREAM_HEADERS: &[&'static str] = &["Event #", "Name", "Type", "Created Date"];
+static TREE_TAB_HEADER: &str = "Streams by Category / Event Type";
+
+// `HEADERS` covers the two flat lists; the stream tree is a third tab appended after them.
+const MAIN_TAB_COUNT: usize = 3;
+const TREE_TAB_INDEX: usize = 2;
+
+// Caps how many events a single keystroke in `Stage::EventSearch` will scan, so typing in a
+// search box stays responsive even against the largest streams we page in (500 events).
+const EVENT_SEARCH_SCAN_LIMIT: usize = 2048;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum Stage {
@@ -19,6 +29,45 @@ enum Stage {
     Stream,
     StreamPreview,
     Search,
+    EventSearch,
+    Filter,
+}
+
+/// A compiled event search term: a regular expression when the typed text parses as one,
+/// otherwise a literal substring match so a bare `(` or `[` doesn't dead-end the search.
+enum EventPattern {
+    Regex(Regex),
+    Literal(String),
+}
+
+impl EventPattern {
+    fn compile(text: &str) -> Option<Self> {
+        if text.is_empty() {
+            return None;
+        }
+
+        match Regex::new(text) {
+            Ok(re) => Some(EventPattern::Regex(re)),
+            Err(_) => Some(EventPattern::Literal(text.to_string())),
+        }
+    }
+
+    fn is_match(&self, haystack: &str) -> bool {
+        match self {
+            EventPattern::Regex(re) => re.is_match(haystack),
+            EventPattern::Literal(needle) => haystack.contains(needle.as_str()),
+        }
+    }
+
+    fn find_ranges(&self, haystack: &str) -> Vec<(usize, usize)> {
+        match self {
+            EventPattern::Regex(re) => re.find_iter(haystack).map(|m| (m.start(), m.end())).collect(),
+            EventPattern::Literal(needle) => haystack
+                .match_indices(needle.as_str())
+                .map(|(start, m)| (start, start + m.len()))
+                .collect(),
+        }
+    }
 }
 
 pub struct StreamsView {
@@ -31,6 +80,19 @@ pub struct StreamsView {
     scroll: u16,
     buffer: String,
     last_error: Option<eventstore::Error>,
+    event_search_buffer: String,
+    event_search_pattern: Option<EventPattern>,
+    event_search_matches: Vec<usize>,
+    event_search_return_stage: Stage,
+    filter_buffer: String,
+    stream_visible_indices: Vec<usize>,
+    tree_visible_indices: Vec<usize>,
+    main_scroll_offsets: Vec<usize>,
+    main_visible_rows: Vec<usize>,
+    stream_scroll_offset: usize,
+    stream_visible_rows: usize,
+    preview_visible_rows: usize,
+    clipboard_status: Option<String>,
 }
 
 impl Default for StreamsView {
@@ -38,21 +100,53 @@ impl Default for StreamsView {
         Self {
             selected_tab: 0,
             selected: 0,
-            main_table_states: vec![TableState::default(), TableState::default()],
+            main_table_states: vec![TableState::default(); MAIN_TAB_COUNT],
             stream_table_state: Default::default(),
             model: Default::default(),
             stage: Stage::Main,
             scroll: 0,
             buffer: Default::default(),
             last_error: None,
+            event_search_buffer: Default::default(),
+            event_search_pattern: None,
+            event_search_matches: Vec::new(),
+            event_search_return_stage: Stage::Stream,
+            filter_buffer: Default::default(),
+            stream_visible_indices: Vec::new(),
+            tree_visible_indices: Vec::new(),
+            main_scroll_offsets: vec![0; MAIN_TAB_COUNT],
+            main_visible_rows: vec![0; MAIN_TAB_COUNT],
+            stream_scroll_offset: 0,
+            stream_visible_rows: 0,
+            preview_visible_rows: 0,
+            clipboard_status: None,
         }
     }
 }
 
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum TreeNodeKind {
+    Category,
+    EventType,
+    Leaf,
+}
+
+/// One row of the collapsible stream tree: either a collapsible `$ce-`/`$et-` group header, or a
+/// leaf naming a concrete stream that can be opened with `Enter`.
+struct TreeNode {
+    kind: TreeNodeKind,
+    label: String,
+    stream_name: Option<String>,
+    indent: usize,
+    collapsed: bool,
+    visible: bool,
+}
+
 #[derive(Default)]
 struct Model {
     last_created: Vec<String>,
     recently_changed: Vec<String>,
+    stream_tree: Vec<TreeNode>,
     selected_stream: Option<String>,
     selected_stream_events: Vec<ResolvedEvent>,
 }
@@ -61,11 +155,71 @@ impl Model {
     fn clear(&mut self) {
         self.last_created.clear();
         self.recently_changed.clear();
+        self.stream_tree.clear();
         self.selected_stream = None;
         self.selected_stream_events.clear();
     }
 }
 
+/// Flattens the `$ce-`/`$et-` groups that `load_streams` buckets out of `$streams` into the rows
+/// the tree tab renders. Top-level group nodes start collapsed so a large database opens to an
+/// uncluttered outline.
+fn build_stream_tree(
+    categories: std::collections::BTreeMap<String, Vec<String>>,
+    event_types: std::collections::BTreeSet<String>,
+) -> Vec<TreeNode> {
+    let mut tree = Vec::new();
+
+    for (category, mut streams) in categories {
+        streams.sort();
+
+        tree.push(TreeNode {
+            kind: TreeNodeKind::Category,
+            label: category,
+            stream_name: None,
+            indent: 0,
+            collapsed: true,
+            visible: true,
+        });
+
+        for stream_name in streams {
+            tree.push(TreeNode {
+                kind: TreeNodeKind::Leaf,
+                label: stream_name.clone(),
+                stream_name: Some(stream_name),
+                indent: 1,
+                collapsed: false,
+                visible: false,
+            });
+        }
+    }
+
+    if !event_types.is_empty() {
+        tree.push(TreeNode {
+            kind: TreeNodeKind::EventType,
+            label: "Event Types".to_string(),
+            stream_name: None,
+            indent: 0,
+            collapsed: true,
+            visible: true,
+        });
+
+        for event_type in event_types {
+            let stream_name = format!("$et-{}", event_type);
+            tree.push(TreeNode {
+                kind: TreeNodeKind::Leaf,
+                label: event_type,
+                stream_name: Some(stream_name),
+                indent: 1,
+                collapsed: false,
+                visible: false,
+            });
+        }
+    }
+
+    tree
+}
+
 impl StreamsView {
     fn load_streams(&mut self, env: &Env) -> eventstore::Result<()> {
         let client = env.client.clone();
@@ -81,8 +235,13 @@ impl StreamsView {
                 .position(StreamPosition::End)
                 .backwards();
 
+            let options_3 = eventstore::ReadStreamOptions::default()
+                .max_count(4096)
+                .position(StreamPosition::Start);
+
             let mut stream_names = client.read_stream("$streams", &options_1).await?;
             let mut all_stream = client.read_all(&options_2).await?;
+            let mut every_stream = client.read_stream("$streams", &options_3).await?;
 
             while let Some(event) = read_stream_next(&mut stream_names).await? {
                 let (_, stream_name) =
@@ -103,11 +262,165 @@ impl StreamsView {
                 model.recently_changed.push(stream_id.clone());
             }
 
+            let mut categories: std::collections::BTreeMap<String, Vec<String>> =
+                Default::default();
+            let mut event_types: std::collections::BTreeSet<String> = Default::default();
+
+            while let Some(event) = read_stream_next(&mut every_stream).await? {
+                let (_, stream_name) =
+                    std::str::from_utf8(event.get_original_event().data.as_ref())
+                        .expect("UTF-8 formatted text")
+                        .rsplit_once('@')
+                        .unwrap_or_default();
+
+                if let Some(event_type) = stream_name.strip_prefix("$et-") {
+                    event_types.insert(event_type.to_string());
+                    continue;
+                }
+
+                if stream_name.starts_with('$') {
+                    continue;
+                }
+
+                let category = stream_name.split_once('-').map_or(stream_name, |(c, _)| c);
+                categories
+                    .entry(category.to_string())
+                    .or_default()
+                    .push(stream_name.to_string());
+            }
+
+            model.stream_tree = build_stream_tree(categories, event_types);
+
             Ok::<_, eventstore::Error>(model)
         })?;
 
+        self.recompute_tree_visibility();
+
         Ok(())
     }
+
+    fn recompute_tree_visibility(&mut self) {
+        let mut parent_collapsed = false;
+
+        for node in self.model.stream_tree.iter_mut() {
+            if node.indent == 0 {
+                parent_collapsed = node.collapsed;
+                node.visible = true;
+            } else {
+                node.visible = !parent_collapsed;
+            }
+        }
+
+        self.tree_visible_indices = self
+            .model
+            .stream_tree
+            .iter()
+            .enumerate()
+            .filter(|(_, node)| node.visible)
+            .map(|(idx, _)| idx)
+            .collect();
+    }
+
+    fn recompute_event_search_matches(&mut self) {
+        self.event_search_matches.clear();
+        self.event_search_pattern = EventPattern::compile(self.event_search_buffer.as_str());
+
+        let pattern = match self.event_search_pattern.as_ref() {
+            Some(pattern) => pattern,
+            None => return,
+        };
+
+        for (idx, event) in self
+            .model
+            .selected_stream_events
+            .iter()
+            .enumerate()
+            .take(EVENT_SEARCH_SCAN_LIMIT)
+        {
+            let recorded = match event.event.as_ref() {
+                Some(recorded) => recorded,
+                None => continue,
+            };
+
+            if pattern.is_match(recorded.event_type.as_str()) {
+                self.event_search_matches.push(idx);
+                continue;
+            }
+
+            if recorded.is_json {
+                if let Ok(text) = std::str::from_utf8(recorded.data.as_ref()) {
+                    if pattern.is_match(text) {
+                        self.event_search_matches.push(idx);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Matches hidden by the active stream filter are skipped, so `n`/`N` never lands
+    /// `self.selected` on a row the `Stream` table isn't currently showing.
+    fn visible_event_matches(&self) -> Vec<usize> {
+        self.event_search_matches
+            .iter()
+            .copied()
+            .filter(|idx| self.stream_visible_indices.contains(idx))
+            .collect()
+    }
+
+    fn advance_event_match(&mut self, forward: bool) {
+        let matches = self.visible_event_matches();
+        if matches.is_empty() {
+            return;
+        }
+
+        let len = matches.len();
+        let next = match matches.iter().position(|&i| i == self.selected) {
+            Some(pos) if forward => (pos + 1) % len,
+            Some(pos) => (pos + len - 1) % len,
+            None => 0,
+        };
+
+        self.selected = matches[next];
+    }
+
+    fn matches_stream_filter(&self, event: &ResolvedEvent) -> bool {
+        if self.filter_buffer.is_empty() {
+            return true;
+        }
+
+        let recorded = match event.event.as_ref() {
+            Some(recorded) => recorded,
+            None => return false,
+        };
+
+        let needle = self.filter_buffer.to_lowercase();
+        if recorded.event_type.to_lowercase().contains(&needle) {
+            return true;
+        }
+
+        format!("{}@{}", recorded.revision, recorded.stream_id)
+            .to_lowercase()
+            .contains(&needle)
+    }
+
+    fn recompute_stream_visible_indices(&mut self) {
+        self.stream_visible_indices = self
+            .model
+            .selected_stream_events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| self.matches_stream_filter(event))
+            .map(|(idx, _)| idx)
+            .collect();
+    }
+
+    /// Drops the in-stream search so stale matches from a previously loaded stream can't move
+    /// `self.selected` past the bounds of a newly loaded, possibly shorter, `selected_stream_events`.
+    fn clear_event_search(&mut self) {
+        self.event_search_buffer.clear();
+        self.event_search_pattern = None;
+        self.event_search_matches.clear();
+    }
 }
 
 impl View for StreamsView {
@@ -122,6 +435,15 @@ impl View for StreamsView {
         self.stage = Stage::Main;
         self.model.clear();
         self.last_error = None;
+        self.clear_event_search();
+        self.filter_buffer.clear();
+        self.stream_visible_indices.clear();
+        self.tree_visible_indices.clear();
+        self.main_scroll_offsets.iter_mut().for_each(|o| *o = 0);
+        self.main_visible_rows.iter_mut().for_each(|o| *o = 0);
+        self.stream_scroll_offset = 0;
+        self.preview_visible_rows = 0;
+        self.clipboard_status = None;
     }
 
     fn refresh(&mut self, env: &Env) -> eventstore::Result<()> {
@@ -130,6 +452,10 @@ impl View for StreamsView {
         }
 
         if let Some(stream_name) = self.model.selected_stream.clone() {
+            // Every entry point into this branch just (re)assigned `selected_stream`, so any
+            // search matches on screen belong to whatever stream was loaded before.
+            self.clear_event_search();
+
             let client = env.client.clone();
             let result = env.handle.block_on(async move {
                 let mut stream = if stream_name.trim() == "$all" {
@@ -167,6 +493,8 @@ impl View for StreamsView {
                 Ok(xs) => self.model.selected_stream_events = xs,
             }
 
+            self.recompute_stream_visible_indices();
+
             Ok(())
         } else {
             self.load_streams(env)
@@ -174,10 +502,25 @@ impl View for StreamsView {
     }
 
     fn draw(&mut self, ctx: ViewCtx, frame: &mut Frame<B>, area: Rect) {
-        match self.stage {
+        let drawn_stage = if self.stage == Stage::EventSearch {
+            self.event_search_return_stage
+        } else if self.stage == Stage::Filter {
+            Stage::Stream
+        } else {
+            self.stage
+        };
+
+        match drawn_stage {
             Stage::Main | Stage::Search => {
                 let rects = Layout::default()
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                    .constraints(
+                        [
+                            Constraint::Percentage(34),
+                            Constraint::Percentage(33),
+                            Constraint::Percentage(33),
+                        ]
+                        .as_ref(),
+                    )
                     .direction(Direction::Horizontal)
                     .margin(2)
                     .split(area);
@@ -190,18 +533,43 @@ impl View for StreamsView {
                         .height(1)
                         .bottom_margin(1);
 
+                    let total_len = match idx {
+                        0 => self.model.last_created.len(),
+                        _ => self.model.recently_changed.len(),
+                    };
+
+                    let (table_area, scrollbar_area) = split_for_scrollbar(rects[idx]);
+                    // Header row + bottom margin + top border eat into the area before any
+                    // data row can be drawn.
+                    let visible_rows = table_area.height.saturating_sub(3) as usize;
+                    self.main_visible_rows[idx] = visible_rows;
+
+                    if self.selected_tab == idx {
+                        keep_selected_in_view(
+                            &mut self.main_scroll_offsets[idx],
+                            self.selected,
+                            visible_rows,
+                        );
+                    }
+
+                    clamp_scroll_offset(&mut self.main_scroll_offsets[idx], total_len, visible_rows);
+
+                    let offset = self.main_scroll_offsets[idx];
+
                     let cells = match idx {
                         0 => self.model.last_created.iter(),
                         _ => self.model.recently_changed.iter(),
                     };
 
                     if self.selected_tab == idx {
-                        self.main_table_states[idx].select(Some(self.selected));
+                        self.main_table_states[idx].select(Some(self.selected.saturating_sub(offset)));
                     } else {
                         self.main_table_states[idx].select(None);
                     }
 
                     let rows = cells
+                        .skip(offset)
+                        .take(visible_rows)
                         .map(|c| {
                             Row::new(vec![
                                 Cell::from(c.as_str()).style(Style::default().fg(Color::Gray))
@@ -209,11 +577,7 @@ impl View for StreamsView {
                         })
                         .collect::<Vec<_>>();
 
-                    let border_type = if idx == 0 {
-                        Borders::TOP | Borders::RIGHT
-                    } else {
-                        Borders::TOP
-                    };
+                    let border_type = Borders::TOP | Borders::RIGHT;
 
                     let table = Table::new(rows)
                         .header(header)
@@ -223,10 +587,12 @@ impl View for StreamsView {
 
                     frame.render_stateful_widget(
                         table,
-                        rects[idx],
+                        table_area,
                         &mut self.main_table_states[idx],
                     );
 
+                    render_scrollbar(frame, scrollbar_area, total_len, visible_rows, offset);
+
                     if let Stage::Search = self.stage {
                         let block = Block::default()
                             .title("Search")
@@ -257,6 +623,89 @@ impl View for StreamsView {
                         frame.render_widget(input, layout[1]);
                     }
                 }
+
+                let header = Row::new(vec![
+                    Cell::from(TREE_TAB_HEADER).style(Style::default().fg(Color::Green)),
+                ])
+                .style(ctx.normal_style)
+                .height(1)
+                .bottom_margin(1);
+
+                let total_len = self.tree_visible_indices.len();
+
+                let (table_area, scrollbar_area) = split_for_scrollbar(rects[TREE_TAB_INDEX]);
+                let visible_rows = table_area.height.saturating_sub(3) as usize;
+                self.main_visible_rows[TREE_TAB_INDEX] = visible_rows;
+
+                let visible_position = if self.selected_tab == TREE_TAB_INDEX {
+                    let position = self
+                        .tree_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                        .unwrap_or(0);
+
+                    keep_selected_in_view(
+                        &mut self.main_scroll_offsets[TREE_TAB_INDEX],
+                        position,
+                        visible_rows,
+                    );
+
+                    Some(position)
+                } else {
+                    None
+                };
+
+                clamp_scroll_offset(
+                    &mut self.main_scroll_offsets[TREE_TAB_INDEX],
+                    total_len,
+                    visible_rows,
+                );
+
+                let offset = self.main_scroll_offsets[TREE_TAB_INDEX];
+
+                let rows = self
+                    .tree_visible_indices
+                    .iter()
+                    .skip(offset)
+                    .take(visible_rows)
+                    .map(|&idx| {
+                        let node = &self.model.stream_tree[idx];
+                        let indent = "  ".repeat(node.indent);
+                        let marker = match node.kind {
+                            TreeNodeKind::Category | TreeNodeKind::EventType => {
+                                if node.collapsed {
+                                    "▸ "
+                                } else {
+                                    "▾ "
+                                }
+                            }
+                            TreeNodeKind::Leaf => "",
+                        };
+
+                        Row::new(vec![Cell::from(format!(
+                            "{}{}{}",
+                            indent, marker, node.label
+                        ))
+                        .style(Style::default().fg(Color::Gray))])
+                    })
+                    .collect::<Vec<_>>();
+
+                let table = Table::new(rows)
+                    .header(header)
+                    .block(Block::default().borders(Borders::TOP))
+                    .highlight_style(ctx.selected_style)
+                    .widths(&[Constraint::Percentage(100)]);
+
+                self.main_table_states[TREE_TAB_INDEX]
+                    .select(visible_position.map(|pos| pos.saturating_sub(offset)));
+
+                frame.render_stateful_widget(
+                    table,
+                    table_area,
+                    &mut self.main_table_states[TREE_TAB_INDEX],
+                );
+
+                render_scrollbar(frame, scrollbar_area, total_len, visible_rows, offset);
             }
             Stage::Stream => {
                 let rects = Layout::default()
@@ -275,9 +724,36 @@ impl View for StreamsView {
                     .height(1)
                     .bottom_margin(1);
 
+                let total_len = self.stream_visible_indices.len();
+
+                let (table_area, scrollbar_area) = split_for_scrollbar(rects[0]);
+                let visible_rows = table_area.height.saturating_sub(3) as usize;
+                self.stream_visible_rows = visible_rows;
+
+                let visible_position = self
+                    .stream_visible_indices
+                    .iter()
+                    .position(|&idx| idx == self.selected)
+                    .unwrap_or(0);
+
+                keep_selected_in_view(
+                    &mut self.stream_scroll_offset,
+                    visible_position,
+                    visible_rows,
+                );
+                clamp_scroll_offset(&mut self.stream_scroll_offset, total_len, visible_rows);
+
+                let offset = self.stream_scroll_offset;
+
                 let mut rows = Vec::new();
 
-                for event in self.model.selected_stream_events.iter() {
+                for &idx in self
+                    .stream_visible_indices
+                    .iter()
+                    .skip(offset)
+                    .take(visible_rows)
+                {
+                    let event = &self.model.selected_stream_events[idx];
                     let rev = event.get_original_event().revision;
                     let event = event.event.as_ref().unwrap();
                     let mut cols = Vec::new();
@@ -298,12 +774,21 @@ impl View for StreamsView {
                     rows.push(Row::new(cols));
                 }
 
+                let title = if self.filter_buffer.is_empty() {
+                    format!("Event Stream '{}'", stream_name)
+                } else {
+                    format!(
+                        "Event Stream '{}' [filter: {}]",
+                        stream_name, self.filter_buffer
+                    )
+                };
+
                 let table = Table::new(rows)
                     .header(header)
                     .block(
                         Block::default()
                             .borders(Borders::TOP)
-                            .title(format!("Event Stream '{}'", stream_name))
+                            .title(title)
                             .title_alignment(Alignment::Right),
                     )
                     .highlight_style(ctx.selected_style)
@@ -314,9 +799,12 @@ impl View for StreamsView {
                         Constraint::Percentage(25),
                     ]);
 
-                self.stream_table_state.select(Some(self.selected));
+                self.stream_table_state
+                    .select(Some(visible_position.saturating_sub(offset)));
 
-                frame.render_stateful_widget(table, rects[0], &mut self.stream_table_state);
+                frame.render_stateful_widget(table, table_area, &mut self.stream_table_state);
+
+                render_scrollbar(frame, scrollbar_area, total_len, visible_rows, offset);
             }
             Stage::StreamPreview => {
                 let rects = Layout::default()
@@ -391,15 +879,19 @@ impl View for StreamsView {
                     "<BINARY>".to_string()
                 };
 
-                let text = Text::from(content);
+                let text = highlight_event_search_matches(content.as_str(), self.event_search_pattern.as_ref());
+
+                let (preview_area, scrollbar_area) = split_for_scrollbar(rects[1]);
+                let total_lines = text.height();
+                self.preview_visible_rows = preview_area.height.saturating_sub(2) as usize;
 
-                if rects[1].height >= 2 + text.height() as u16 {
+                if preview_area.height >= 2 + text.height() as u16 {
                     // We lock scrolling as everything is visible.
                     self.scroll = 0;
-                } else if self.scroll > (2 + text.height() as u16) - rects[1].height {
+                } else if self.scroll > (2 + text.height() as u16) - preview_area.height {
                     // We cap how much we can scroll. It will be difficult to do that part during
                     // the refresh call as the user might have resized the terminal.
-                    self.scroll = (2 + text.height() as u16) - rects[1].height;
+                    self.scroll = (2 + text.height() as u16) - preview_area.height;
                 }
 
                 let paragraph = Paragraph::new(text)
@@ -407,8 +899,63 @@ impl View for StreamsView {
                     .block(Block::default().borders(Borders::BOTTOM | Borders::TOP))
                     .scroll((self.scroll, 0));
 
-                frame.render_widget(paragraph, rects[1])
+                frame.render_widget(paragraph, preview_area);
+
+                render_scrollbar(
+                    frame,
+                    scrollbar_area,
+                    total_lines,
+                    preview_area.height.saturating_sub(2) as usize,
+                    self.scroll as usize,
+                );
             }
+            // `drawn_stage` folds `EventSearch`/`Filter` back to the stage they overlay, so
+            // these arms only exist to keep the match exhaustive.
+            Stage::EventSearch | Stage::Filter => {}
+        }
+
+        if self.stage == Stage::EventSearch {
+            let block = Block::default()
+                .title("Find in stream")
+                .borders(Borders::ALL)
+                .style(Style::default().add_modifier(Modifier::REVERSED));
+            let area = centered_rect(40, 15, frame.size());
+            frame.render_widget(Clear, area);
+            frame.render_widget(block, area);
+
+            let layout = Layout::default()
+                .margin(2)
+                .constraints([Constraint::Length(13), Constraint::Max(100)])
+                .direction(Direction::Horizontal)
+                .split(area);
+
+            let label = Paragraph::new("Pattern: ").style(Style::default().fg(Color::Gray));
+
+            frame.render_widget(label, layout[0]);
+
+            let mut input = std::iter::repeat('_').take(100).collect::<String>();
+
+            let char_count = self.event_search_buffer.chars().count();
+            input.replace_range(..char_count, self.event_search_buffer.as_str());
+
+            let input = Paragraph::new(input).style(Style::default().fg(Color::Gray));
+
+            frame.render_widget(input, layout[1]);
+        }
+
+        if self.stage == Stage::Filter {
+            let bar = Rect {
+                x: area.x,
+                y: area.y + area.height.saturating_sub(1),
+                width: area.width,
+                height: 1,
+            };
+
+            let prompt = Paragraph::new(format!("Filter: {}_", self.filter_buffer))
+                .style(Style::default().add_modifier(Modifier::REVERSED));
+
+            frame.render_widget(Clear, bar);
+            frame.render_widget(prompt, bar);
         }
 
         if let Some(e) = self.last_error.as_ref() {
@@ -436,6 +983,29 @@ impl View for StreamsView {
 
             frame.render_widget(label, rect);
         }
+
+        if let Some(message) = self.clipboard_status.as_ref() {
+            let block = Block::default()
+                .title("Clipboard")
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::Black).fg(Color::Yellow));
+            let area = centered_rect(40, 20, frame.size());
+            frame.render_widget(Clear, area);
+            frame.render_widget(block, area);
+
+            let rect = Layout::default()
+                .margin(2)
+                .constraints([Constraint::Percentage(100)])
+                .direction(Direction::Horizontal)
+                .split(area)[0];
+
+            let label = Paragraph::new(format!("{}. Press 'q' to close.", message))
+                .style(Style::default().fg(Color::Gray))
+                .wrap(Wrap { trim: false });
+
+            frame.render_widget(label, rect);
+        }
     }
 
     fn on_key_pressed(&mut self, key: KeyCode) -> Request {
@@ -449,6 +1019,14 @@ impl View for StreamsView {
             return Request::Noop;
         }
 
+        if self.clipboard_status.is_some() {
+            if let KeyCode::Char('q' | 'Q') = key {
+                self.clipboard_status = None;
+            }
+
+            return Request::Noop;
+        }
+
         if self.stage == Stage::Search {
             match key {
                 KeyCode::Esc => self.stage = Stage::Main,
@@ -458,6 +1036,8 @@ impl View for StreamsView {
                 KeyCode::Enter => {
                     self.selected = 0;
                     self.stage = Stage::Stream;
+                    self.filter_buffer.clear();
+                    self.clear_event_search();
                     self.model.selected_stream =
                         Some(std::mem::replace(&mut self.buffer, Default::default()));
                     return Request::Refresh;
@@ -469,14 +1049,79 @@ impl View for StreamsView {
             return Request::Noop;
         }
 
+        if self.stage == Stage::EventSearch {
+            match key {
+                KeyCode::Esc => self.stage = self.event_search_return_stage,
+                KeyCode::Backspace => {
+                    self.event_search_buffer.pop();
+                    self.recompute_event_search_matches();
+                }
+                KeyCode::Enter => {
+                    self.recompute_event_search_matches();
+                    self.stage = self.event_search_return_stage;
+
+                    if let Some(&first) = self.visible_event_matches().first() {
+                        self.selected = first;
+                    }
+                }
+                KeyCode::Char(c) if c.is_ascii() => {
+                    self.event_search_buffer.push(c);
+                    self.recompute_event_search_matches();
+                }
+                _ => {}
+            }
+
+            return Request::Noop;
+        }
+
+        if self.stage == Stage::Filter {
+            match key {
+                KeyCode::Esc => {
+                    self.filter_buffer.clear();
+                    self.recompute_stream_visible_indices();
+                    self.stage = Stage::Stream;
+                }
+                KeyCode::Enter => self.stage = Stage::Stream,
+                KeyCode::Backspace => {
+                    self.filter_buffer.pop();
+                    self.recompute_stream_visible_indices();
+                    self.selected = self.stream_visible_indices.first().copied().unwrap_or(0);
+                }
+                KeyCode::Char(c) if c.is_ascii() => {
+                    self.filter_buffer.push(c);
+                    self.recompute_stream_visible_indices();
+                    self.selected = self.stream_visible_indices.first().copied().unwrap_or(0);
+                }
+                _ => {}
+            }
+
+            return Request::Noop;
+        }
+
+        // Outside the text-entry stages above, `j`/`k`/`h`/`l`/`g`/`G` normalize into the same
+        // movement keys the arrow-key arms below already handle, so the two input styles stay
+        // behaviorally identical. Half-page movement has no vi alias here: `KeyCode` alone
+        // can't see the Ctrl modifier needed to tell `Ctrl-d`/`Ctrl-u` apart from plain `d`/`u`,
+        // so it's bound directly to the real PageDown/PageUp keys below instead.
+        let key = match key {
+            KeyCode::Char('j') => KeyCode::Down,
+            KeyCode::Char('k') => KeyCode::Up,
+            KeyCode::Char('h') => KeyCode::Left,
+            KeyCode::Char('l') => KeyCode::Right,
+            KeyCode::Char('g') => KeyCode::Home,
+            KeyCode::Char('G') => KeyCode::End,
+            other => other,
+        };
+
         match key {
             KeyCode::Char('q' | 'Q') => {
                 return match self.stage {
                     Stage::Main => Request::Exit,
-                    Stage::Search => Request::Noop,
+                    Stage::Search | Stage::EventSearch | Stage::Filter => Request::Noop,
                     Stage::Stream => {
                         self.stage = Stage::Main;
                         self.selected = 0;
+                        self.clear_event_search();
                         Request::Noop
                     }
                     Stage::StreamPreview => {
@@ -487,27 +1132,139 @@ impl View for StreamsView {
                 }
             }
 
-            KeyCode::Char('/') => {
-                if self.stage == Stage::Main {
-                    self.stage = Stage::Search;
+            KeyCode::Char('/') => match self.stage {
+                Stage::Main => self.stage = Stage::Search,
+                Stage::Stream | Stage::StreamPreview => {
+                    self.event_search_return_stage = self.stage;
+                    self.event_search_buffer.clear();
+                    self.stage = Stage::EventSearch;
+                }
+                _ => {}
+            },
+
+            KeyCode::Char('n')
+                if matches!(self.stage, Stage::Stream | Stage::StreamPreview)
+                    && !self.event_search_matches.is_empty() =>
+            {
+                self.advance_event_match(true);
+            }
+
+            KeyCode::Char('N')
+                if matches!(self.stage, Stage::Stream | Stage::StreamPreview)
+                    && !self.event_search_matches.is_empty() =>
+            {
+                self.advance_event_match(false);
+            }
+            KeyCode::Left
+                if self.stage == Stage::Main && self.selected_tab == TREE_TAB_INDEX =>
+            {
+                if let Some(node) = self.model.stream_tree.get_mut(self.selected) {
+                    if matches!(node.kind, TreeNodeKind::Category | TreeNodeKind::EventType) {
+                        node.collapsed = true;
+                    }
+                }
+                self.recompute_tree_visibility();
+            }
+
+            KeyCode::Right
+                if self.stage == Stage::Main && self.selected_tab == TREE_TAB_INDEX =>
+            {
+                if let Some(node) = self.model.stream_tree.get_mut(self.selected) {
+                    if matches!(node.kind, TreeNodeKind::Category | TreeNodeKind::EventType) {
+                        node.collapsed = false;
+                    }
                 }
+                self.recompute_tree_visibility();
             }
+
             KeyCode::Left | KeyCode::Right => {
-                self.selected_tab = (self.selected_tab + 1) % 2;
+                self.selected_tab = if key == KeyCode::Left {
+                    (self.selected_tab + MAIN_TAB_COUNT - 1) % MAIN_TAB_COUNT
+                } else {
+                    (self.selected_tab + 1) % MAIN_TAB_COUNT
+                };
                 self.selected = 0;
+                self.clear_event_search();
             }
 
-            KeyCode::Up => {
-                if self.stage == Stage::StreamPreview {
+            KeyCode::Tab if self.stage == Stage::Main => {
+                self.selected_tab = (self.selected_tab + 1) % MAIN_TAB_COUNT;
+                self.selected = 0;
+                self.clear_event_search();
+            }
+
+            KeyCode::Char('f') if self.stage == Stage::Stream => {
+                self.stage = Stage::Filter;
+            }
+
+            KeyCode::Char('y' | 'Y')
+                if matches!(self.stage, Stage::Stream | Stage::StreamPreview) =>
+            {
+                if let Some(event) = self.model.selected_stream_events.get(self.selected) {
+                    let text = if key == KeyCode::Char('Y') {
+                        event_metadata_for_clipboard(event)
+                    } else {
+                        event_payload_for_clipboard(event)
+                    };
+
+                    self.clipboard_status = match copy_to_clipboard(text.as_str()) {
+                        Ok(()) => None,
+                        Err(e) => Some(format!("Could not copy to clipboard: {}", e)),
+                    };
+                }
+            }
+
+            KeyCode::Up => match self.stage {
+                Stage::StreamPreview => {
                     if self.scroll > 0 {
                         self.scroll -= 1;
                     }
-                } else if self.selected > 0 {
-                    self.selected -= 1;
                 }
-            }
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(pos) = self
+                        .tree_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        if pos > 0 {
+                            self.selected = self.tree_visible_indices[pos - 1];
+                        }
+                    } else if let Some(&first) = self.tree_visible_indices.first() {
+                        self.selected = first;
+                    }
+                }
+                Stage::Stream => {
+                    if let Some(pos) = self
+                        .stream_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        if pos > 0 {
+                            self.selected = self.stream_visible_indices[pos - 1];
+                        }
+                    }
+                }
+                _ => {
+                    if self.selected > 0 {
+                        self.selected -= 1;
+                    }
+                }
+            },
 
             KeyCode::Down => match self.stage {
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(pos) = self
+                        .tree_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        if pos + 1 < self.tree_visible_indices.len() {
+                            self.selected = self.tree_visible_indices[pos + 1];
+                        }
+                    } else if let Some(&first) = self.tree_visible_indices.first() {
+                        self.selected = first;
+                    }
+                }
                 Stage::Main => {
                     let len = if self.selected_tab == 0 {
                         self.model.last_created.len()
@@ -515,13 +1272,21 @@ impl View for StreamsView {
                         self.model.recently_changed.len()
                     };
 
-                    if self.selected < len - 1 {
+                    if len > 0 && self.selected < len - 1 {
                         self.selected += 1;
                     }
                 }
                 Stage::Stream => {
-                    if self.selected < self.model.selected_stream_events.len() - 1 {
-                        self.selected += 1;
+                    if let Some(pos) = self
+                        .stream_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        if pos + 1 < self.stream_visible_indices.len() {
+                            self.selected = self.stream_visible_indices[pos + 1];
+                        }
+                    } else if let Some(&first) = self.stream_visible_indices.first() {
+                        self.selected = first;
                     }
                 }
                 Stage::StreamPreview => {
@@ -531,6 +1296,167 @@ impl View for StreamsView {
                 _ => {}
             },
 
+            // `g`/`G` normalize to `Home`/`End`: jump to the first/last row, or to the top/bottom
+            // of the rendered JSON in `StreamPreview`.
+            KeyCode::Home => match self.stage {
+                Stage::StreamPreview => self.scroll = 0,
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(&first) = self.tree_visible_indices.first() {
+                        self.selected = first;
+                    }
+                }
+                Stage::Main => self.selected = 0,
+                Stage::Stream => {
+                    if let Some(&first) = self.stream_visible_indices.first() {
+                        self.selected = first;
+                    }
+                }
+                _ => {}
+            },
+
+            KeyCode::End => match self.stage {
+                // Clamped back down to the last fully-visible line by the scroll-bounds check in
+                // `draw`, same as a manual scroll past the end would be.
+                Stage::StreamPreview => self.scroll = u16::MAX,
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(&last) = self.tree_visible_indices.last() {
+                        self.selected = last;
+                    }
+                }
+                Stage::Main => {
+                    let len = if self.selected_tab == 0 {
+                        self.model.last_created.len()
+                    } else {
+                        self.model.recently_changed.len()
+                    };
+
+                    self.selected = len.saturating_sub(1);
+                }
+                Stage::Stream => {
+                    if let Some(&last) = self.stream_visible_indices.last() {
+                        self.selected = last;
+                    }
+                }
+                _ => {}
+            },
+
+            // PageDown/PageUp move half a viewport at a time.
+            KeyCode::PageDown => match self.stage {
+                Stage::StreamPreview => {
+                    let step = (self.preview_visible_rows / 2).max(1) as u16;
+                    self.scroll = self.scroll.saturating_add(step);
+                }
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(pos) = self
+                        .tree_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        let target = half_page_target(
+                            pos,
+                            self.tree_visible_indices.len(),
+                            self.main_visible_rows[TREE_TAB_INDEX],
+                            true,
+                        );
+                        self.selected = self.tree_visible_indices[target];
+                    }
+                }
+                Stage::Main => {
+                    let len = if self.selected_tab == 0 {
+                        self.model.last_created.len()
+                    } else {
+                        self.model.recently_changed.len()
+                    };
+
+                    self.selected =
+                        half_page_target(self.selected, len, self.main_visible_rows[self.selected_tab], true);
+                }
+                Stage::Stream => {
+                    if let Some(pos) = self
+                        .stream_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        let target = half_page_target(
+                            pos,
+                            self.stream_visible_indices.len(),
+                            self.stream_visible_rows,
+                            true,
+                        );
+                        self.selected = self.stream_visible_indices[target];
+                    }
+                }
+                _ => {}
+            },
+
+            KeyCode::PageUp => match self.stage {
+                Stage::StreamPreview => {
+                    let step = (self.preview_visible_rows / 2).max(1) as u16;
+                    self.scroll = self.scroll.saturating_sub(step);
+                }
+                Stage::Main if self.selected_tab == TREE_TAB_INDEX => {
+                    if let Some(pos) = self
+                        .tree_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        let target = half_page_target(
+                            pos,
+                            self.tree_visible_indices.len(),
+                            self.main_visible_rows[TREE_TAB_INDEX],
+                            false,
+                        );
+                        self.selected = self.tree_visible_indices[target];
+                    }
+                }
+                Stage::Main => {
+                    let len = if self.selected_tab == 0 {
+                        self.model.last_created.len()
+                    } else {
+                        self.model.recently_changed.len()
+                    };
+
+                    self.selected =
+                        half_page_target(self.selected, len, self.main_visible_rows[self.selected_tab], false);
+                }
+                Stage::Stream => {
+                    if let Some(pos) = self
+                        .stream_visible_indices
+                        .iter()
+                        .position(|&idx| idx == self.selected)
+                    {
+                        let target = half_page_target(
+                            pos,
+                            self.stream_visible_indices.len(),
+                            self.stream_visible_rows,
+                            false,
+                        );
+                        self.selected = self.stream_visible_indices[target];
+                    }
+                }
+                _ => {}
+            },
+
+            KeyCode::Enter if self.stage == Stage::Main && self.selected_tab == TREE_TAB_INDEX => {
+                match self.model.stream_tree.get(self.selected) {
+                    Some(TreeNode { kind: TreeNodeKind::Leaf, stream_name: Some(name), .. }) => {
+                        self.stage = Stage::Stream;
+                        self.model.selected_stream = Some(name.clone());
+                        self.selected = 0;
+                        self.filter_buffer.clear();
+                        self.clear_event_search();
+
+                        return Request::Refresh;
+                    }
+                    Some(node) if matches!(node.kind, TreeNodeKind::Category | TreeNodeKind::EventType) => {
+                        let collapsed = !node.collapsed;
+                        self.model.stream_tree[self.selected].collapsed = collapsed;
+                        self.recompute_tree_visibility();
+                    }
+                    _ => {}
+                }
+            }
+
             KeyCode::Enter => {
                 if self.stage == Stage::Main {
                     self.stage = Stage::Stream;
@@ -543,6 +1469,8 @@ impl View for StreamsView {
 
                     self.model.selected_stream = Some(rows[self.selected].clone());
                     self.selected = 0;
+                    self.filter_buffer.clear();
+                    self.clear_event_search();
 
                     return Request::Refresh;
                 } else if self.stage == Stage::Stream {
@@ -560,25 +1488,262 @@ impl View for StreamsView {
 
     fn keybindings(&self) -> &[(&str, &str)] {
         match self.stage {
-            Stage::StreamPreview => &[("↑", "Scroll up"), ("↓", "Scroll down"), ("q", "Close")],
+            Stage::StreamPreview => &[
+                ("↑ / k", "Scroll up"),
+                ("↓ / j", "Scroll down"),
+                ("g / G", "Jump to top / bottom"),
+                ("PgDn / PgUp", "Half-page scroll"),
+                ("/", "Find in stream"),
+                ("n", "Next match"),
+                ("N", "Previous match"),
+                ("y", "Copy payload"),
+                ("Y", "Copy metadata"),
+                ("q", "Close"),
+            ],
             Stage::Stream => &[
-                ("↑", "Scroll up"),
-                ("↓", "Scroll down"),
+                ("↑ / k", "Scroll up"),
+                ("↓ / j", "Scroll down"),
+                ("g / G", "Jump to first / last row"),
+                ("PgDn / PgUp", "Half-page scroll"),
+                ("/", "Find in stream"),
+                ("n", "Next match"),
+                ("N", "Previous match"),
+                ("f", "Filter"),
+                ("y", "Copy payload"),
+                ("Y", "Copy metadata"),
                 ("Enter", "Select"),
                 ("q", "Close"),
             ],
+            Stage::EventSearch => &[("Enter", "Confirm"), ("Esc", "Cancel")],
+            Stage::Filter => &[("Enter", "Close"), ("Esc", "Clear filter")],
             Stage::Main | Stage::Search => &[
-                ("↑", "Scroll up"),
-                ("↓", "Scroll down"),
-                ("→", "Move right"),
-                ("← ", "Move left"),
+                ("↑ / k", "Scroll up"),
+                ("↓ / j", "Scroll down"),
+                ("→ / l", "Move right"),
+                ("← / h", "Move left"),
+                ("g / G", "Jump to first / last row"),
+                ("PgDn / PgUp", "Half-page scroll"),
+                ("Tab", "Next tab"),
                 ("/", "Search"),
-                ("Enter", "Select"),
+                ("Enter", "Select / toggle"),
             ],
         }
     }
 }
 
+/// Splits a table/paragraph area into its content column and a 1-wide column reserved for the
+/// scrollbar rendered by [`render_scrollbar`].
+fn split_for_scrollbar(area: Rect) -> (Rect, Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    (chunks[0], chunks[1])
+}
+
+/// Nudges `offset` so `selected` stays inside the `[offset, offset + visible_rows)` window.
+fn keep_selected_in_view(offset: &mut usize, selected: usize, visible_rows: usize) {
+    if visible_rows == 0 {
+        return;
+    }
+
+    if selected < *offset {
+        *offset = selected;
+    } else if selected >= *offset + visible_rows {
+        *offset = selected + 1 - visible_rows;
+    }
+}
+
+/// Computes the row position after a half-viewport jump (PageDown/PageUp), clamped to `[0, len)`
+/// (0 when `len` is 0).
+fn half_page_target(position: usize, len: usize, visible_rows: usize, forward: bool) -> usize {
+    let step = (visible_rows / 2).max(1);
+
+    if forward {
+        (position + step).min(len.saturating_sub(1))
+    } else {
+        position.saturating_sub(step)
+    }
+}
+
+/// Clamps `offset` so the viewport never scrolls past the point where it would show trailing
+/// blank rows, e.g. after the underlying row count shrinks (a filter narrows, a resize shrinks
+/// the terminal).
+fn clamp_scroll_offset(offset: &mut usize, total_rows: usize, visible_rows: usize) {
+    let max_offset = total_rows.saturating_sub(visible_rows);
+    if *offset > max_offset {
+        *offset = max_offset;
+    }
+}
+
+/// Computes the scrollbar thumb's size and starting row within a track `height` rows tall.
+/// Only meaningful when `total_rows > visible_rows > 0`; `render_scrollbar` draws a blank
+/// column instead otherwise.
+fn scrollbar_thumb_metrics(total_rows: usize, visible_rows: usize, height: usize, offset: usize) -> (usize, usize) {
+    let max_offset = total_rows - visible_rows;
+    let thumb_size = ((visible_rows * height) / total_rows).max(1).min(height);
+    let thumb_start = if max_offset == 0 {
+        0
+    } else {
+        ((offset * (height - thumb_size)) / max_offset).min(height - thumb_size)
+    };
+
+    (thumb_size, thumb_start)
+}
+
+/// Renders a thumb/track scrollbar into `area` (expected to be 1 column wide) proportional to
+/// `visible_rows / total_rows`.
+fn render_scrollbar(frame: &mut Frame<B>, area: Rect, total_rows: usize, visible_rows: usize, offset: usize) {
+    if area.height == 0 {
+        return;
+    }
+
+    let height = area.height as usize;
+    let lines = if total_rows <= visible_rows || visible_rows == 0 {
+        vec![Spans::from(" "); height]
+    } else {
+        let (thumb_size, thumb_start) = scrollbar_thumb_metrics(total_rows, visible_rows, height, offset);
+
+        (0..height)
+            .map(|row| {
+                let ch = if row >= thumb_start && row < thumb_start + thumb_size {
+                    "█"
+                } else {
+                    "│"
+                };
+
+                Spans::from(ch)
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(Text::from(lines)).style(Style::default().fg(Color::DarkGray));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Renders the payload a `y` keypress copies to the clipboard: pretty-printed JSON for JSON
+/// events, or a hex dump of the raw bytes for binary ones.
+fn event_payload_for_clipboard(event: &ResolvedEvent) -> String {
+    let recorded = match event.event.as_ref() {
+        Some(recorded) => recorded,
+        None => return String::new(),
+    };
+
+    if recorded.is_json {
+        match serde_json::from_slice::<serde_json::Value>(recorded.data.as_ref()) {
+            Ok(json) => serde_json::to_string_pretty(&json).unwrap_or_default(),
+            Err(_) => String::from_utf8_lossy(recorded.data.as_ref()).to_string(),
+        }
+    } else {
+        to_hex(recorded.data.as_ref())
+    }
+}
+
+/// Renders the compact metadata line a `Y` keypress copies to the clipboard.
+fn event_metadata_for_clipboard(event: &ResolvedEvent) -> String {
+    let recorded = match event.event.as_ref() {
+        Some(recorded) => recorded,
+        None => return String::new(),
+    };
+
+    format!(
+        "{}@{}, {}, {}",
+        recorded.revision, recorded.stream_id, recorded.event_type, recorded.created
+    )
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Copies `text` to the system clipboard via an OSC 52 escape sequence written straight to
+/// stdout, so a `y`/`Y` keypress works over SSH without this view needing a clipboard handle
+/// threaded in from outside.
+fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    write!(
+        std::io::stdout(),
+        "\x1b]52;c;{}\x07",
+        base64_encode(text.as_bytes())
+    )?;
+    std::io::stdout().flush()
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => BASE64_ALPHABET[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+
+    out
+}
+
+/// Turns rendered JSON into `Text`, styling any substrings matched by the active event search
+/// so `n`/`N` jumps land somewhere visibly highlighted in `Stage::StreamPreview`.
+fn highlight_event_search_matches<'a>(content: &str, pattern: Option<&EventPattern>) -> Text<'a> {
+    let pattern = match pattern {
+        Some(pattern) => pattern,
+        None => return Text::from(content.to_string()),
+    };
+
+    let mut lines = Vec::new();
+
+    for line in content.lines() {
+        let ranges = pattern.find_ranges(line);
+        if ranges.is_empty() {
+            lines.push(Spans::from(line.to_string()));
+            continue;
+        }
+
+        let mut spans = Vec::new();
+        let mut cursor = 0;
+
+        for (start, end) in ranges {
+            if start > cursor {
+                spans.push(Span::raw(line[cursor..start].to_string()));
+            }
+
+            spans.push(Span::styled(
+                line[start..end].to_string(),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+            cursor = end;
+        }
+
+        if cursor < line.len() {
+            spans.push(Span::raw(line[cursor..].to_string()));
+        }
+
+        lines.push(Spans::from(spans));
+    }
+
+    Text::from(lines)
+}
+
 async fn read_stream_next(
     stream: &mut eventstore::ReadStream,
 ) -> eventstore::Result<Option<eventstore::ResolvedEvent>> {
@@ -593,3 +1758,123 @@ async fn read_stream_next(
         Ok(v) => Ok(v),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_page_target_steps_half_the_viewport() {
+        assert_eq!(half_page_target(0, 100, 20, true), 10);
+        assert_eq!(half_page_target(10, 100, 20, false), 0);
+    }
+
+    #[test]
+    fn half_page_target_clamps_to_bounds() {
+        assert_eq!(half_page_target(95, 100, 20, true), 99);
+        assert_eq!(half_page_target(5, 100, 20, false), 0);
+    }
+
+    #[test]
+    fn half_page_target_handles_empty_list() {
+        assert_eq!(half_page_target(0, 0, 20, true), 0);
+        assert_eq!(half_page_target(0, 0, 20, false), 0);
+    }
+
+    #[test]
+    fn half_page_target_steps_at_least_one_row_with_no_viewport() {
+        assert_eq!(half_page_target(0, 10, 0, true), 1);
+        assert_eq!(half_page_target(2, 10, 0, false), 1);
+    }
+
+    #[test]
+    fn keep_selected_in_view_is_noop_when_already_inside() {
+        let mut offset = 5;
+        keep_selected_in_view(&mut offset, 10, 20);
+        assert_eq!(offset, 5);
+    }
+
+    #[test]
+    fn keep_selected_in_view_pulls_offset_down_to_selection_above_it() {
+        let mut offset = 10;
+        keep_selected_in_view(&mut offset, 3, 20);
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn keep_selected_in_view_pushes_offset_up_to_selection_below_it() {
+        let mut offset = 0;
+        keep_selected_in_view(&mut offset, 25, 10);
+        assert_eq!(offset, 16);
+    }
+
+    #[test]
+    fn keep_selected_in_view_is_noop_with_zero_visible_rows() {
+        let mut offset = 5;
+        keep_selected_in_view(&mut offset, 0, 0);
+        assert_eq!(offset, 5);
+    }
+
+    #[test]
+    fn clamp_scroll_offset_pulls_offset_back_when_rows_shrink() {
+        let mut offset = 40;
+        clamp_scroll_offset(&mut offset, 50, 20);
+        assert_eq!(offset, 30);
+
+        clamp_scroll_offset(&mut offset, 5, 20);
+        assert_eq!(offset, 0);
+    }
+
+    #[test]
+    fn clamp_scroll_offset_leaves_offset_untouched_when_still_valid() {
+        let mut offset = 3;
+        clamp_scroll_offset(&mut offset, 50, 20);
+        assert_eq!(offset, 3);
+    }
+
+    #[test]
+    fn scrollbar_thumb_metrics_starts_at_track_top_when_nothing_is_scrolled() {
+        let (size, start) = scrollbar_thumb_metrics(100, 20, 10, 0);
+        assert_eq!(size, 2);
+        assert_eq!(start, 0);
+    }
+
+    #[test]
+    fn scrollbar_thumb_metrics_reaches_track_bottom_at_max_offset() {
+        let (size, start) = scrollbar_thumb_metrics(100, 20, 10, 80);
+        assert_eq!(size, 2);
+        assert_eq!(start, 8);
+        assert!(start + size <= 10);
+    }
+
+    #[test]
+    fn scrollbar_thumb_metrics_never_shrinks_below_one_row() {
+        let (size, _) = scrollbar_thumb_metrics(1000, 1, 10, 0);
+        assert_eq!(size, 1);
+    }
+
+    #[test]
+    fn event_pattern_compile_returns_none_for_empty_text() {
+        assert!(EventPattern::compile("").is_none());
+    }
+
+    #[test]
+    fn event_pattern_compile_falls_back_to_literal_on_invalid_regex() {
+        let pattern = EventPattern::compile("(unclosed").expect("non-empty text always compiles");
+        assert!(pattern.is_match("has (unclosed in it"));
+        assert!(!pattern.is_match("no match here"));
+    }
+
+    #[test]
+    fn event_pattern_is_match_and_find_ranges_for_regex() {
+        let pattern = EventPattern::compile(r"ev\d+").expect("valid regex");
+        assert!(pattern.is_match("event ev42 happened"));
+        assert_eq!(pattern.find_ranges("ev1 and ev22"), vec![(0, 3), (8, 12)]);
+    }
+
+    #[test]
+    fn event_pattern_find_ranges_for_literal_returns_no_matches_when_absent() {
+        let pattern = EventPattern::compile("(unclosed").expect("non-empty text always compiles");
+        assert!(pattern.find_ranges("nothing to see").is_empty());
+    }
+}